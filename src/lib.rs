@@ -12,6 +12,9 @@
 
 extern crate embedded_hal as hal;
 
+#[cfg(feature = "async")]
+extern crate embedded_hal_async as hal_async;
+
 extern crate nb;
 
 
@@ -46,6 +49,7 @@ pub struct Hx711<D, IN, OUT> {
     dout: IN,
     pd_sck: OUT,
     mode: Mode,
+    rate: SampleRate,
 }
 
 /// Error type for Input and Output errors on digital pins.
@@ -53,11 +57,14 @@ pub struct Hx711<D, IN, OUT> {
 /// If you use the driver with such a crate, you can use `.into_ok()` on all results
 /// instead of `.unwrap()` or `.expect()`.
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<EIN, EOUT> {
     /// Error while reading a digital pin
     Input(EIN),
     /// Error while writing a digital pin
     Output(EOUT),
+    /// A filtering read was requested with a sample count of zero.
+    InvalidSampleCount,
 }
 
 /// For some hardware crates, the digital input and output pins can never fail.
@@ -92,11 +99,47 @@ where
             dout,
             pd_sck,
             mode: Mode::ChAGain128,
+            rate: SampleRate::Sps10,
         };
         hx711.reset()?;
         Ok(hx711)
     }
 
+    /// Creates a new driver, also driving the RATE pin to select the output
+    /// data rate.
+    ///
+    /// The RATE pin is set according to `rate` and then released; the chosen
+    /// rate is stored so higher-level helpers (averaging, timeouts) can reason
+    /// about the expected conversion latency via [`get_rate`](Hx711::get_rate).
+    /// For a board that wires RATE to a fixed level, use [`new`](Hx711::new)
+    /// instead.
+    pub fn new_with_rate<ROUT>(
+        delay: D,
+        dout: IN,
+        pd_sck: OUT,
+        mut rate_pin: ROUT,
+        rate: SampleRate,
+    ) -> Result<Self, Error<EIN, EOUT>>
+    where
+        ROUT: OutputPin<Error = EOUT>,
+    {
+        match rate {
+            SampleRate::Sps10 => rate_pin.set_low().map_err(Error::Output)?,
+            SampleRate::Sps80 => rate_pin.set_high().map_err(Error::Output)?,
+        }
+        let mut hx711 = Self::new(delay, dout, pd_sck)?;
+        hx711.rate = rate;
+        Ok(hx711)
+    }
+
+    /// Get the configured output data rate.
+    ///
+    /// For a driver created with [`new`](Hx711::new) this is always
+    /// [`SampleRate::Sps10`], whatever level the RATE pin is wired to.
+    pub fn get_rate(&self) -> SampleRate {
+        self.rate
+    }
+
     /// Get the mode (channel and gain).
     pub fn get_mode(&self) -> Mode {
         self.mode
@@ -161,10 +204,167 @@ where
 
         Ok(i24_to_i32(count))
     }
+
+    /// Block for a single conversion, counting each `WouldBlock` as a retry.
+    fn block_retrieve(&mut self, retries: &mut u32) -> Result<i32, Error<EIN, EOUT>> {
+        loop {
+            match self.retrieve() {
+                Ok(value) => return Ok(value),
+                Err(nb::Error::WouldBlock) => *retries += 1,
+                Err(nb::Error::Other(e)) => return Err(e),
+            }
+        }
+    }
+
+    /// Block for `n` conversions and return their arithmetic mean.
+    ///
+    /// Samples are accumulated into an `i64` to avoid overflow across the 24-bit
+    /// range before dividing. The second element of the tuple is the number of
+    /// `WouldBlock` retries spent waiting on DOUT. The count is returned only
+    /// once all `n` conversions have completed; while DOUT stays high the call
+    /// keeps polling and does not return.
+    ///
+    /// Returns [`Error::InvalidSampleCount`] if `n` is zero.
+    pub fn read_averaged(&mut self, n: u16) -> Result<(i32, u32), Error<EIN, EOUT>> {
+        if n == 0 {
+            return Err(Error::InvalidSampleCount);
+        }
+        let mut sum: i64 = 0;
+        let mut retries = 0;
+        for _ in 0..n {
+            sum += i64::from(self.block_retrieve(&mut retries)?);
+        }
+        Ok(((sum / n as i64) as i32, retries))
+    }
+
+    /// Block for `N` conversions and return their median.
+    ///
+    /// Samples are collected into a fixed-capacity buffer on the stack, sorted
+    /// in place and the middle element returned; for even `N` the two middle
+    /// elements are averaged. The second element of the tuple is the number of
+    /// `WouldBlock` retries spent waiting on DOUT, as for
+    /// [`read_averaged`](Hx711::read_averaged).
+    ///
+    /// Returns [`Error::InvalidSampleCount`] if `N` is zero.
+    pub fn read_median<const N: usize>(&mut self) -> Result<(i32, u32), Error<EIN, EOUT>> {
+        if N == 0 {
+            return Err(Error::InvalidSampleCount);
+        }
+        let mut buf = [0i32; N];
+        let mut retries = 0;
+        for slot in buf.iter_mut() {
+            *slot = self.block_retrieve(&mut retries)?;
+        }
+        Ok((median(&mut buf), retries))
+    }
+}
+
+/// Async HX711 driver, built on the `embedded-hal-async` traits.
+///
+/// This mirrors [`Hx711`] bit for bit, but instead of returning
+/// [`nb::Error::WouldBlock`] while DOUT is still high it suspends the task on
+/// [`Wait::wait_for_low`] until the chip signals that a conversion is ready,
+/// and each timing gap awaits the async [`DelayNs`] instead of busy-waiting.
+/// This lets the driver share the executor cooperatively with other tasks.
+///
+/// [`Wait::wait_for_low`]: hal_async::digital::Wait::wait_for_low
+/// [`DelayNs`]: hal_async::delay::DelayNs
+#[cfg(feature = "async")]
+pub struct Hx711Async<D, IN, OUT> {
+    delay: D,
+    dout: IN,
+    pd_sck: OUT,
+    mode: Mode,
+}
+
+#[cfg(feature = "async")]
+impl<D, IN, OUT, EIN, EOUT> Hx711Async<D, IN, OUT>
+where
+    D: hal_async::delay::DelayNs,
+    IN: hal_async::digital::Wait + InputPin<Error = EIN>,
+    OUT: OutputPin<Error = EOUT>,
+{
+    /// Creates a new driver from Input and Outut pins
+    pub async fn new(delay: D, dout: IN, mut pd_sck: OUT) -> Result<Self, Error<EIN, EOUT>> {
+        pd_sck.set_low().map_err(Error::Output)?;
+        let mut hx711 = Hx711Async {
+            delay,
+            dout,
+            pd_sck,
+            mode: Mode::ChAGain128,
+        };
+        hx711.reset().await?;
+        Ok(hx711)
+    }
+
+    /// Get the mode (channel and gain).
+    pub fn get_mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Set the mode (channel and gain).
+    pub async fn set_mode(&mut self, mode: Mode) -> Result<(), Error<EIN, EOUT>> {
+        self.mode = mode;
+        self.retrieve().await.and(Ok(()))
+    }
+
+    /// Put the chip in power down state.
+    pub async fn disable(&mut self) -> Result<(), Error<EIN, EOUT>> {
+        self.pd_sck.set_high().map_err(Error::Output)?;
+        self.delay.delay_us(TIME_TO_SLEEP).await;
+        Ok(())
+    }
+
+    /// Wake the chip up and set mode.
+    pub async fn enable(&mut self) -> Result<(), Error<EIN, EOUT>> {
+        self.pd_sck.set_low().map_err(Error::Output)?;
+        self.delay.delay_us(TIME_SCK_LOW).await;
+        self.set_mode(self.mode).await
+    }
+
+    /// Reset the chip.
+    pub async fn reset(&mut self) -> Result<(), Error<EIN, EOUT>> {
+        self.disable().await?;
+        self.enable().await
+    }
+
+    /// Retrieve the next conversion value, awaiting until one is ready.
+    pub async fn retrieve(&mut self) -> Result<i32, Error<EIN, EOUT>> {
+        self.pd_sck.set_low().map_err(Error::Output)?;
+        // Suspend until DOUT goes low, signalling that a conversion is ready.
+        self.dout.wait_for_low().await.map_err(Error::Input)?;
+        self.delay.delay_us(TIME_BEFORE_READOUT).await;
+
+        let mut count: i32 = 0;
+        for _ in 0..24 {
+            // Read 24 bits
+            count <<= 1;
+            self.pd_sck.set_high().map_err(Error::Output)?;
+            self.delay.delay_us(TIME_SCK_HIGH).await;
+            self.pd_sck.set_low().map_err(Error::Output)?;
+
+            if self.dout.is_high().map_err(Error::Input)? {
+                count += 1;
+            }
+            self.delay.delay_us(TIME_SCK_LOW).await;
+        }
+
+        // Continue to set mode for next conversion
+        let n_reads = self.mode as u16;
+        for _ in 0..n_reads {
+            self.pd_sck.set_high().map_err(Error::Output)?;
+            self.delay.delay_us(TIME_SCK_HIGH).await;
+            self.pd_sck.set_low().map_err(Error::Output)?;
+            self.delay.delay_us(TIME_SCK_LOW).await;
+        }
+
+        Ok(i24_to_i32(count))
+    }
 }
 
 /// The HX711 can run in three modes (see Table 3 in Datasheet):
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Mode {
     /// Chanel A with factor 128 gain
     ChAGain128 = 1,
@@ -174,6 +374,238 @@ pub enum Mode {
     ChAGain64 = 3,
 }
 
+/// A calibration layer that turns raw HX711 counts into physical units.
+///
+/// Wraps an [`Hx711`] together with a zero `offset` and a counts-per-unit
+/// `scale` factor. Calibrate once with [`tare`](Scale::tare) (to capture the
+/// no-load reading) and [`set_scale`](Scale::set_scale) (the counts produced
+/// by one unit of load), then read calibrated values with
+/// [`get_units`](Scale::get_units), which computes `(raw - offset) / scale`.
+///
+/// All arithmetic is `f32` so the wrapper stays `no_std`.
+pub struct Scale<D, IN, OUT> {
+    hx711: Hx711<D, IN, OUT>,
+    offset: f32,
+    scale: f32,
+}
+
+impl<D, IN, OUT, EIN, EOUT> Scale<D, IN, OUT>
+where
+    D: DelayNs,
+    IN: InputPin<Error = EIN>,
+    OUT: OutputPin<Error = EOUT>,
+{
+    /// Wrap an existing driver with a zero offset of `0.0` and a scale of `1.0`.
+    ///
+    /// Until [`set_scale`](Scale::set_scale) is called, `get_units` returns the
+    /// offset-corrected raw count.
+    pub fn new(hx711: Hx711<D, IN, OUT>) -> Self {
+        Scale {
+            hx711,
+            offset: 0.0,
+            scale: 1.0,
+        }
+    }
+
+    /// Set the counts-per-unit factor relating raw counts to physical units.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    /// Get the currently configured counts-per-unit factor.
+    pub fn get_scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Establish the zero offset by averaging `samples` raw conversions.
+    ///
+    /// Returns [`Error::InvalidSampleCount`] if `samples` is zero.
+    pub fn tare(&mut self, samples: u16) -> Result<(), Error<EIN, EOUT>> {
+        if samples == 0 {
+            return Err(Error::InvalidSampleCount);
+        }
+        let mut sum: i64 = 0;
+        for _ in 0..samples {
+            sum += i64::from(nb::block!(self.hx711.retrieve())?);
+        }
+        self.offset = mean(sum, samples);
+        Ok(())
+    }
+
+    /// Get the currently configured zero offset, in raw counts.
+    pub fn get_offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Read a single calibrated value, `(raw - offset) / scale`.
+    pub fn get_units(&mut self) -> Result<f32, Error<EIN, EOUT>> {
+        let raw = nb::block!(self.hx711.retrieve())?;
+        Ok(to_units(raw, self.offset, self.scale))
+    }
+
+    /// Consume the wrapper and return the underlying driver.
+    pub fn into_inner(self) -> Hx711<D, IN, OUT> {
+        self.hx711
+    }
+}
+
+/// A single-channel ADC abstraction, modeled on the `AdcChannel` trait of
+/// `embedded-hal`/`embedded-hal-async`.
+///
+/// `embedded-hal` 1.0 does not ship an ADC trait, so the shape is reproduced
+/// here: a [`read`](adc::AdcChannel::read) method returning a signed sample and
+/// an associated [`Error`](adc::Error) type whose [`kind`](adc::Error::kind)
+/// maps driver-specific failures onto a small, HAL-agnostic
+/// [`ErrorKind`](adc::ErrorKind). Implementing it lets the
+/// HX711 be consumed by generic code that treats it as just another ADC.
+pub mod adc {
+    /// A HAL-agnostic categorisation of ADC errors.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    #[non_exhaustive]
+    pub enum ErrorKind {
+        /// Error talking to the underlying digital input pin.
+        Input,
+        /// Error talking to the underlying digital output pin.
+        Output,
+        /// A different error, not covered by the variants above.
+        Other,
+    }
+
+    /// An ADC error that can be categorised into an [`ErrorKind`].
+    pub trait Error: core::fmt::Debug {
+        /// Map this error onto a HAL-agnostic [`ErrorKind`].
+        fn kind(&self) -> ErrorKind;
+    }
+
+    /// Associates an [`Error`] type with an ADC channel.
+    pub trait ErrorType {
+        /// The error type surfaced by [`AdcChannel::read`].
+        type Error: Error;
+    }
+
+    /// A single-channel ADC that yields one signed sample per read.
+    pub trait AdcChannel: ErrorType {
+        /// Read a single conversion, blocking until one is available.
+        fn read(&mut self) -> Result<i32, Self::Error>;
+    }
+
+    /// The async counterpart of [`AdcChannel`], awaiting until a sample is ready.
+    #[cfg(feature = "async")]
+    pub trait AdcChannelAsync: ErrorType {
+        /// Read a single conversion, awaiting until one is available.
+        // No `Send` bound is placed on the returned future: like the
+        // `embedded-hal-async` traits this is meant to run on single-core
+        // cooperative executors, so the auto-trait lint is not relevant here.
+        #[allow(async_fn_in_trait)]
+        async fn read(&mut self) -> Result<i32, Self::Error>;
+    }
+}
+
+impl<EIN, EOUT> adc::Error for Error<EIN, EOUT>
+where
+    EIN: core::fmt::Debug,
+    EOUT: core::fmt::Debug,
+{
+    fn kind(&self) -> adc::ErrorKind {
+        match self {
+            Error::Input(_) => adc::ErrorKind::Input,
+            Error::Output(_) => adc::ErrorKind::Output,
+            Error::InvalidSampleCount => adc::ErrorKind::Other,
+        }
+    }
+}
+
+impl<D, IN, OUT, EIN, EOUT> adc::ErrorType for Hx711<D, IN, OUT>
+where
+    IN: InputPin<Error = EIN>,
+    OUT: OutputPin<Error = EOUT>,
+    EIN: core::fmt::Debug,
+    EOUT: core::fmt::Debug,
+{
+    type Error = Error<EIN, EOUT>;
+}
+
+impl<D, IN, OUT, EIN, EOUT> adc::AdcChannel for Hx711<D, IN, OUT>
+where
+    D: DelayNs,
+    IN: InputPin<Error = EIN>,
+    OUT: OutputPin<Error = EOUT>,
+    EIN: core::fmt::Debug,
+    EOUT: core::fmt::Debug,
+{
+    fn read(&mut self) -> Result<i32, Self::Error> {
+        nb::block!(self.retrieve())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, IN, OUT, EIN, EOUT> adc::ErrorType for Hx711Async<D, IN, OUT>
+where
+    IN: hal_async::digital::Wait + InputPin<Error = EIN>,
+    OUT: OutputPin<Error = EOUT>,
+    EIN: core::fmt::Debug,
+    EOUT: core::fmt::Debug,
+{
+    type Error = Error<EIN, EOUT>;
+}
+
+#[cfg(feature = "async")]
+impl<D, IN, OUT, EIN, EOUT> adc::AdcChannelAsync for Hx711Async<D, IN, OUT>
+where
+    D: hal_async::delay::DelayNs,
+    IN: hal_async::digital::Wait + InputPin<Error = EIN>,
+    OUT: OutputPin<Error = EOUT>,
+    EIN: core::fmt::Debug,
+    EOUT: core::fmt::Debug,
+{
+    async fn read(&mut self) -> Result<i32, Self::Error> {
+        self.retrieve().await
+    }
+}
+
+/// Output data rate selected by the HX711 RATE pin (see Table 2 in Datasheet).
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SampleRate {
+    /// 10 samples per second (RATE pin low). This is the power-on default.
+    Sps10,
+    /// 80 samples per second (RATE pin high).
+    Sps80,
+}
+
+impl SampleRate {
+    /// The nominal output data rate in samples per second.
+    pub fn samples_per_second(self) -> u16 {
+        match self {
+            SampleRate::Sps10 => 10,
+            SampleRate::Sps80 => 80,
+        }
+    }
+}
+
+/// Sort `buf` in place and return its median (averaging the two middle
+/// elements for an even length).
+fn median(buf: &mut [i32]) -> i32 {
+    buf.sort_unstable();
+    let n = buf.len();
+    if n % 2 == 1 {
+        buf[n / 2]
+    } else {
+        ((i64::from(buf[n / 2 - 1]) + i64::from(buf[n / 2])) / 2) as i32
+    }
+}
+
+/// Arithmetic mean of a pre-summed set of `samples` raw counts, as an offset.
+fn mean(sum: i64, samples: u16) -> f32 {
+    sum as f32 / samples as f32
+}
+
+/// Apply a zero offset and counts-per-unit scale to a raw count.
+fn to_units(raw: i32, offset: f32, scale: f32) -> f32 {
+    (raw as f32 - offset) / scale
+}
+
 /// Convert 24 bit signed integer to i32
 fn i24_to_i32(x: i32) -> i32 {
     if x >= 0x800000 {
@@ -201,6 +633,36 @@ mod tests {
         assert_eq!(i24_to_i32(0x7FFFFF), 8388607);
     }
 
+    #[test]
+    fn median_odd() {
+        let mut buf = [5, 1, 3];
+        assert_eq!(median(&mut buf), 3);
+    }
+
+    #[test]
+    fn median_even() {
+        // two middle elements (3 and 7 after sorting) are averaged
+        let mut buf = [7, 1, 9, 3];
+        assert_eq!(median(&mut buf), 5);
+        // rounds towards zero like integer division
+        let mut buf = [10, 1, 2, 3];
+        assert_eq!(median(&mut buf), 2);
+    }
+
+    #[test]
+    fn offset_mean() {
+        assert_eq!(mean(40, 4), 10.0);
+        assert_eq!(mean(-30, 3), -10.0);
+    }
+
+    #[test]
+    fn units_from_raw() {
+        // (raw - offset) / scale
+        assert_eq!(to_units(1100, 100.0, 10.0), 100.0);
+        // a scale of 1.0 just subtracts the offset
+        assert_eq!(to_units(42, 42.0, 1.0), 0.0);
+    }
+
     #[test]
     #[cfg(feature = "never_type")]
     fn infallible_into_ok() {